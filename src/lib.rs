@@ -2,6 +2,9 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
 
 use ed25519_dalek::{Signer, Verifier};
 use nt::abi::FunctionExt;
@@ -9,7 +12,6 @@ use nt::utils::Clock;
 use ton_block::{Deserializable, Serializable};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
-use zeroize::Zeroize;
 
 use crate::models::*;
 use crate::tokens_object::*;
@@ -69,17 +71,7 @@ pub fn get_expected_address(
     public_key: Option<String>,
     init_data: TokensObject,
 ) -> Result<ExpectedAddress, JsValue> {
-    let mut state_init = ton_block::StateInit::construct_from_base64(tvc).handle_error()?;
-    let contract_abi = parse_contract_abi(contract_abi)?;
-    let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
-
-    state_init.data = if let Some(data) = state_init.data.take() {
-        Some(insert_init_data(contract_abi, data.into(), &public_key, init_data)?.into_cell())
-    } else {
-        None
-    };
-
-    let cell = state_init.serialize().handle_error()?;
+    let cell = make_state_init_with_data(tvc, contract_abi, public_key, init_data)?;
     let repr_hash = cell.repr_hash().to_hex_string();
 
     Ok(ObjectBuilder::new()
@@ -94,6 +86,41 @@ pub fn get_expected_address(
         .unchecked_into())
 }
 
+#[wasm_bindgen(js_name = "verifyTvcAddress")]
+pub fn verify_tvc_address(
+    tvc: &str,
+    contract_abi: &str,
+    workchain_id: i8,
+    public_key: Option<String>,
+    init_data: TokensObject,
+    expected_address: &str,
+) -> Result<bool, JsValue> {
+    let expected_address = parse_address(expected_address)?;
+    let cell = make_state_init_with_data(tvc, contract_abi, public_key, init_data)?;
+
+    Ok(expected_address.workchain_id() == workchain_id as i32
+        && expected_address.address().get_bytestring(0) == cell.repr_hash().as_slice())
+}
+
+fn make_state_init_with_data(
+    tvc: &str,
+    contract_abi: &str,
+    public_key: Option<String>,
+    init_data: TokensObject,
+) -> Result<ton_types::Cell, JsValue> {
+    let mut state_init = ton_block::StateInit::construct_from_base64(tvc).handle_error()?;
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
+
+    state_init.data = if let Some(data) = state_init.data.take() {
+        Some(insert_init_data(contract_abi, data.into(), &public_key, init_data)?.into_cell())
+    } else {
+        None
+    };
+
+    state_init.serialize().handle_error()
+}
+
 #[wasm_bindgen(js_name = "getBocHash")]
 pub fn get_boc_hash(boc: &str) -> Result<String, JsValue> {
     Ok(parse_cell(boc)?.repr_hash().to_hex_string())
@@ -124,9 +151,14 @@ pub fn unpack_from_cell(
 
 #[wasm_bindgen(js_name = "extractPublicKey")]
 pub fn extract_public_key(boc: &str) -> Result<String, JsValue> {
-    use nt::core::ton_wallet::{highload_wallet_v2, wallet_v3};
-
     let account_stuff = parse_account_stuff(boc)?;
+    extract_account_public_key(&account_stuff).map(hex::encode)
+}
+
+fn extract_account_public_key(
+    account_stuff: &ton_block::AccountStuff,
+) -> Result<ed25519_dalek::PublicKey, JsValue> {
+    use nt::core::ton_wallet::{highload_wallet_v2, wallet_v3};
 
     let state_init = match &account_stuff.storage.state {
         ton_block::AccountState::AccountActive { state_init, .. } => state_init,
@@ -144,7 +176,6 @@ pub fn extract_public_key(boc: &str) -> Result<String, JsValue> {
                 .handle_error()
                 .and_then(|init_data| {
                     ed25519_dalek::PublicKey::from_bytes(init_data.public_key.as_slice())
-                        .map(hex::encode)
                         .handle_error()
                 });
         } else if highload_wallet_v2::is_highload_wallet_v2(&code_hash) {
@@ -152,7 +183,6 @@ pub fn extract_public_key(boc: &str) -> Result<String, JsValue> {
                 .handle_error()
                 .and_then(|init_data| {
                     ed25519_dalek::PublicKey::from_bytes(init_data.public_key.as_slice())
-                        .map(hex::encode)
                         .handle_error()
                 });
         }
@@ -164,7 +194,6 @@ pub fn extract_public_key(boc: &str) -> Result<String, JsValue> {
         .handle_error()?;
 
     ed25519_dalek::PublicKey::from_bytes(&data)
-        .map(hex::encode)
         .map_err(|_| nt::abi::ExtractionError::InvalidPublicKey)
         .handle_error()
 }
@@ -480,17 +509,32 @@ pub fn generate_ed25519_key_pair() -> Result<Ed25519KeyPair, JsValue> {
 #[wasm_bindgen(js_name = "ed25519_sign")]
 pub fn sign_data(secret_key: &str, data: &str) -> Result<String, JsValue> {
     let data = parse_hex_or_base64_bytes(data).handle_error()?;
-
-    let mut secret_key = parse_hex_or_base64_bytes(secret_key).handle_error()?;
-    let secret = ed25519_dalek::SecretKey::from_bytes(&secret_key).handle_error()?;
-    secret_key.zeroize();
-
-    let public = ed25519_dalek::PublicKey::from(&secret);
-    let key_pair = ed25519_dalek::Keypair { secret, public };
+    let key_pair = parse_key_pair(secret_key)?;
     let signature = key_pair.sign(&data);
     Ok(base64::encode(signature.to_bytes()))
 }
 
+#[wasm_bindgen(js_name = "generateSigningTestVector")]
+pub fn generate_signing_test_vector(
+    secret_key: &str,
+    data: &str,
+) -> Result<SigningTestVector, JsValue> {
+    use sha2::Digest;
+
+    let data = parse_hex_or_base64_bytes(data).handle_error()?;
+    let key_pair = parse_key_pair(secret_key)?;
+
+    let data_hash = sha2::Sha256::digest(&data);
+    let signature = key_pair.sign(&data_hash);
+
+    Ok(ObjectBuilder::new()
+        .set("publicKey", hex::encode(key_pair.public.as_bytes()))
+        .set("dataHash", hex::encode(data_hash))
+        .set("signature", base64::encode(signature.to_bytes()))
+        .build()
+        .unchecked_into())
+}
+
 #[wasm_bindgen(js_name = "extendSignature")]
 pub fn extend_signature(signature: &str) -> Result<ExtendedSignature, JsValue> {
     let signature = parse_signature(signature)?;
@@ -534,20 +578,32 @@ pub fn create_unsigned_message_without_signature(
     let time = clock.inner.now_ms_u64();
     let expire_at = ExpireAt::new_from_millis(Expiration::Timeout(timeout), time);
 
-    let mut header = HashMap::with_capacity(3);
-    header.insert("time".to_string(), ton_abi::TokenValue::Time(time));
-    header.insert(
-        "expire".to_string(),
-        ton_abi::TokenValue::Expire(expire_at.timestamp),
-    );
-    header.insert("pubkey".to_string(), ton_abi::TokenValue::PublicKey(None));
+    // Build message
+    let message =
+        make_unsigned_external_message(time, expire_at.timestamp, dst, method, state_init, &input)?;
+
+    // Serialize message
+    make_signed_message(nt::crypto::SignedMessage {
+        message,
+        expire_at: expire_at.timestamp,
+    })
+}
+
+fn make_unsigned_external_message(
+    time: u64,
+    expire_at: u32,
+    dst: ton_block::MsgAddressInt,
+    method: &ton_abi::Function,
+    state_init: Option<ton_block::StateInit>,
+    input: &[ton_abi::Token],
+) -> Result<ton_block::Message, JsValue> {
+    let header = make_unsigned_header(time, expire_at);
 
     // Encode body
     let body = method
-        .encode_input(&header, &input, false, None, Some(dst.clone()))
+        .encode_input(&header, input, false, None, Some(dst.clone()))
         .handle_error()?;
 
-    // Build message
     let mut message =
         ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
             dst,
@@ -558,11 +614,15 @@ pub fn create_unsigned_message_without_signature(
     }
     message.set_body(body.into());
 
-    // Serialize message
-    make_signed_message(nt::crypto::SignedMessage {
-        message,
-        expire_at: expire_at.timestamp,
-    })
+    Ok(message)
+}
+
+fn make_unsigned_header(time: u64, expire_at: u32) -> HashMap<String, ton_abi::TokenValue> {
+    let mut header = HashMap::with_capacity(3);
+    header.insert("time".to_string(), ton_abi::TokenValue::Time(time));
+    header.insert("expire".to_string(), ton_abi::TokenValue::Expire(expire_at));
+    header.insert("pubkey".to_string(), ton_abi::TokenValue::PublicKey(None));
+    header
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -713,3 +773,1106 @@ pub fn wallet_prepare_transfer(
         ton_wallet::TransferAction::DeployFirst => None,
     })
 }
+
+#[wasm_bindgen(js_name = "decodeValidatorSet")]
+pub fn decode_validator_set(
+    config_boc: &str,
+    which: ValidatorSetKind,
+) -> Result<Option<ValidatorSet>, JsValue> {
+    let config = parse_config_params(config_boc)?;
+    let param = parse_validator_set_kind(which)?;
+
+    let validator_set = match config.config(param).handle_error()? {
+        Some(ton_block::ConfigParamEnum::ConfigParam32(param)) => param.prev_validators,
+        Some(ton_block::ConfigParamEnum::ConfigParam34(param)) => param.cur_validators,
+        Some(ton_block::ConfigParamEnum::ConfigParam36(param)) => param.next_validators,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(make_validator_set(&validator_set)))
+}
+
+#[wasm_bindgen(js_name = "assertMessageMethod")]
+pub fn assert_message_method(
+    message_boc: &str,
+    contract_abi: &str,
+    expected_method: &str,
+) -> Result<bool, JsValue> {
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let expected_method = contract_abi.function(expected_method).handle_error()?;
+
+    let method = guess_message_method(message_boc, &contract_abi)?;
+    Ok(matches!(method, Some(method) if method.name == expected_method.name))
+}
+
+#[wasm_bindgen(js_name = "getMessageMethod")]
+pub fn get_message_method(
+    message_boc: &str,
+    contract_abi: &str,
+) -> Result<Option<String>, JsValue> {
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let method = guess_message_method(message_boc, &contract_abi)?;
+    Ok(method.map(|method| method.name.clone()))
+}
+
+fn guess_message_method<'a>(
+    message_boc: &str,
+    contract_abi: &'a ton_abi::Contract,
+) -> Result<Option<&'a ton_abi::Function>, JsValue> {
+    let message = ton_block::Message::construct_from_base64(message_boc.trim()).handle_error()?;
+    let body = match message.body() {
+        Some(body) => body,
+        None => return Ok(None),
+    };
+
+    nt::abi::guess_method_by_input(
+        contract_abi,
+        &body,
+        &nt::abi::MethodName::Guess,
+        message.is_internal(),
+    )
+    .handle_error()
+}
+
+#[wasm_bindgen(js_name = "parseBlockId")]
+pub fn parse_block_id_string(block_id: &str) -> Result<BlockId, JsValue> {
+    parse_block_id_str(block_id)
+        .handle_error()
+        .map(|block_id| make_block_id(&block_id))
+}
+
+#[wasm_bindgen(js_name = "formatBlockId")]
+pub fn format_block_id(block_id: BlockId) -> Result<String, JsValue> {
+    parse_block_id(block_id).map(|block_id| block_id_to_string(&block_id))
+}
+
+#[wasm_bindgen(js_name = "getLibraryCellHash")]
+pub fn get_library_cell_hash(boc: &str) -> Result<String, JsValue> {
+    let cell = parse_cell(boc)?;
+
+    // Library reference cell already contains the hash of the library cell
+    if cell.cell_type() == ton_types::CellType::LibraryReference {
+        return match cell.data().get(1..33) {
+            Some(hash) => Ok(hex::encode(hash)),
+            None => Err("Invalid library reference cell").handle_error(),
+        };
+    }
+
+    Ok(cell.repr_hash().to_hex_string())
+}
+
+#[wasm_bindgen(js_name = "decodeDePoolParticipant")]
+pub fn decode_depool_participant(
+    clock: &ClockWithOffset,
+    account_stuff_boc: &str,
+    contract_abi: &str,
+    participant_address: &str,
+) -> Result<Option<TokensObject>, JsValue> {
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let method = contract_abi.function("getParticipantInfo").handle_error()?;
+
+    let input = match method.inputs.as_slice() {
+        [param] if param.kind == ton_abi::ParamType::Address => {
+            vec![parse_token(param, JsValue::from_str(participant_address)).handle_error()?]
+        }
+        _ => return Err("Unexpected getParticipantInfo signature").handle_error(),
+    };
+
+    let output = method
+        .run_local(clock.inner.as_ref(), account_stuff, &input)
+        .handle_error()?;
+
+    // DePool rejects the getter call for non-participants
+    match output.tokens {
+        Some(tokens) if output.result_code == 0 => make_tokens_object(tokens).map(Some),
+        _ => Ok(None),
+    }
+}
+
+#[wasm_bindgen(js_name = "estimateGas")]
+pub fn estimate_gas(
+    clock: &ClockWithOffset,
+    account_stuff_boc: &str,
+    contract_abi: &str,
+    method: &str,
+    input: TokensObject,
+    config_boc: &str,
+) -> Result<String, JsValue> {
+    use ton_executor::TransactionExecutor;
+
+    // Parse params
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let method = contract_abi.function(method).handle_error()?;
+    let input = parse_tokens_object(&method.inputs, input).handle_error()?;
+    let config = ton_executor::BlockchainConfig::with_config(parse_config_params(config_boc)?)
+        .handle_error()?;
+
+    // Build message
+    let dst = account_stuff.addr.clone();
+    let message = ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
+        dst: dst.clone(),
+        ..Default::default()
+    });
+    let expiration = nt::core::models::Expiration::Timeout(ESTIMATE_GAS_MESSAGE_TIMEOUT);
+
+    // Sign the message on behalf of the contract owner (if any) with a fake signature,
+    // so that methods guarded by `msg.pubkey()` reach `tvm.accept()`
+    let message = match extract_account_public_key(&account_stuff) {
+        Ok(public_key) => {
+            nt::core::utils::make_labs_unsigned_message(
+                clock.inner.as_ref(),
+                message,
+                expiration,
+                &public_key,
+                Cow::Owned(method.clone()),
+                input,
+            )
+            .and_then(|message| message.sign(&[0; 64]))
+            .handle_error()?
+            .message
+        }
+        Err(_) => {
+            let time = clock.inner.now_ms_u64();
+            let expire_at = nt::core::models::ExpireAt::new_from_millis(expiration, time);
+            let header = make_unsigned_header(time, expire_at.timestamp);
+
+            let body = method
+                .encode_input(&header, &input, false, None, Some(dst))
+                .handle_error()?;
+
+            let mut message = message;
+            message.set_body(body.into());
+            message
+        }
+    };
+
+    // Execute message
+    let lt = account_stuff.storage.last_trans_lt + 1;
+    let mut account = ton_block::Account::Account(account_stuff)
+        .serialize()
+        .handle_error()?;
+
+    let params = ton_executor::ExecuteParams {
+        block_unixtime: clock.inner.now_sec_u64() as u32,
+        block_lt: lt,
+        last_tr_lt: Arc::new(AtomicU64::new(lt)),
+        behavior_modifiers: Some(ton_vm::executor::BehaviorModifiers {
+            chksig_always_succeed: true,
+        }),
+        ..Default::default()
+    };
+
+    let transaction = ton_executor::OrdinaryTransactionExecutor::new(config)
+        .execute_with_params(Some(&message), &mut account, params)
+        .handle_error()?;
+
+    match transaction.read_description().handle_error()? {
+        ton_block::TransactionDescr::Ordinary(description) => match description.compute_ph {
+            ton_block::TrComputePhase::Vm(phase) => Ok(phase.gas_used.0.to_string()),
+            ton_block::TrComputePhase::Skipped(phase) => {
+                Err(format!("Compute phase skipped: {:?}", phase.reason)).handle_error()
+            }
+        },
+        _ => Err("Unexpected transaction type").handle_error(),
+    }
+}
+
+// Estimation message is executed immediately, so any reasonable timeout works
+const ESTIMATE_GAS_MESSAGE_TIMEOUT: u32 = 60;
+
+#[wasm_bindgen(js_name = "buildNftItemContent")]
+pub fn build_nft_item_content(
+    collection_content: &str,
+    individual_content: &str,
+) -> Result<String, JsValue> {
+    // Collection content cell stores collection metadata in the first reference
+    // and the common prefix of items content in the second one
+    let common_content = parse_cell(collection_content)?
+        .reference(1)
+        .map(ton_types::SliceData::from)
+        .handle_error()?;
+
+    // Individual content is the cell stored in the item itself
+    let individual_content = parse_cell(individual_content)?;
+
+    let mut builder = ton_types::BuilderData::new();
+    builder
+        .append_u8(OFFCHAIN_CONTENT_TAG)
+        .and_then(|builder| builder.checked_append_references_and_data(&common_content))
+        .and_then(|builder| builder.checked_append_reference(individual_content))
+        .handle_error()?;
+
+    make_boc(&builder.into_cell().handle_error()?)
+}
+
+const OFFCHAIN_CONTENT_TAG: u8 = 0x01;
+
+#[wasm_bindgen(js_name = "computeJettonWalletAddress")]
+pub fn compute_jetton_wallet_address(
+    jetton_root: &str,
+    owner_address: &str,
+    wallet_code: &str,
+) -> Result<String, JsValue> {
+    let jetton_root = parse_address(jetton_root)?;
+    let owner_address = parse_address(owner_address)?;
+    let wallet_code = parse_cell(wallet_code)?;
+
+    // TEP-74 wallet data: balance, owner address, jetton master address, wallet code
+    let mut data = ton_types::BuilderData::new();
+    ton_block::Grams(0).write_to(&mut data).handle_error()?;
+    owner_address.write_to(&mut data).handle_error()?;
+    jetton_root.write_to(&mut data).handle_error()?;
+    data.checked_append_reference(wallet_code.clone())
+        .handle_error()?;
+
+    let state_init = ton_block::StateInit {
+        code: Some(wallet_code),
+        data: Some(data.into_cell().handle_error()?),
+        ..Default::default()
+    };
+
+    let repr_hash = state_init.serialize().handle_error()?.repr_hash();
+    Ok(format!(
+        "{}:{}",
+        jetton_root.workchain_id(),
+        repr_hash.to_hex_string()
+    ))
+}
+
+#[wasm_bindgen(js_name = "decodeJettonTransfer")]
+pub fn decode_jetton_transfer(body: &str) -> Result<Option<JettonTransfer>, JsValue> {
+    let mut body = parse_cell_slice(body)?;
+    match body.get_next_u32() {
+        Ok(JETTON_TRANSFER_OPCODE) => {}
+        _ => return Ok(None),
+    }
+
+    let query_id = body.get_next_u64().handle_error()?;
+    let amount = ton_block::Grams::construct_from(&mut body).handle_error()?;
+    let destination = ton_block::MsgAddress::construct_from(&mut body).handle_error()?;
+    let response_destination = ton_block::MsgAddress::construct_from(&mut body).handle_error()?;
+    let custom_payload = if body.get_next_bit().handle_error()? {
+        Some(body.checked_drain_reference().handle_error()?)
+    } else {
+        None
+    };
+    let forward_amount = ton_block::Grams::construct_from(&mut body).handle_error()?;
+    let forward_payload = if body.get_next_bit().handle_error()? {
+        Some(body.checked_drain_reference().handle_error()?)
+    } else if body.remaining_bits() > 0 || body.remaining_references() > 0 {
+        Some(body.into_cell())
+    } else {
+        None
+    };
+
+    Ok(Some(
+        ObjectBuilder::new()
+            .set("queryId", query_id.to_string())
+            .set("amount", amount.0.to_string())
+            .set("destination", destination.to_string())
+            .set(
+                "responseDestination",
+                match response_destination {
+                    ton_block::MsgAddress::AddrNone => None,
+                    address => Some(address.to_string()),
+                },
+            )
+            .set(
+                "customPayload",
+                custom_payload.as_ref().map(make_boc).transpose()?,
+            )
+            .set("forwardAmount", forward_amount.0.to_string())
+            .set(
+                "forwardPayload",
+                forward_payload.as_ref().map(make_boc).transpose()?,
+            )
+            .build()
+            .unchecked_into(),
+    ))
+}
+
+const JETTON_TRANSFER_OPCODE: u32 = 0x0f8a7ea5;
+
+#[wasm_bindgen(js_name = "buildJettonTransfer")]
+pub fn build_jetton_transfer(transfer: JettonTransfer) -> Result<String, JsValue> {
+    fn write_maybe_ref(
+        builder: &mut ton_types::BuilderData,
+        cell: Option<ton_types::Cell>,
+    ) -> Result<(), JsValue> {
+        match cell {
+            Some(cell) => builder
+                .append_bit_one()
+                .and_then(|builder| builder.checked_append_reference(cell)),
+            None => builder.append_bit_zero(),
+        }
+        .map(|_| ())
+        .handle_error()
+    }
+
+    let transfer = parse_jetton_transfer(transfer)?;
+
+    let mut builder = ton_types::BuilderData::new();
+    builder
+        .append_u32(JETTON_TRANSFER_OPCODE)
+        .and_then(|builder| builder.append_u64(transfer.query_id))
+        .handle_error()?;
+    transfer.amount.write_to(&mut builder).handle_error()?;
+    transfer.destination.write_to(&mut builder).handle_error()?;
+    match transfer.response_destination {
+        Some(address) => address.write_to(&mut builder),
+        None => ton_block::MsgAddress::AddrNone.write_to(&mut builder),
+    }
+    .handle_error()?;
+    write_maybe_ref(&mut builder, transfer.custom_payload)?;
+    transfer
+        .forward_amount
+        .write_to(&mut builder)
+        .handle_error()?;
+    write_maybe_ref(&mut builder, transfer.forward_payload)?;
+
+    make_boc(&builder.into_cell().handle_error()?)
+}
+
+#[wasm_bindgen(js_name = "getTransactionStats")]
+pub fn get_transaction_stats(transaction: &str) -> Result<TransactionStats, JsValue> {
+    let transaction =
+        ton_block::Transaction::construct_from_base64(transaction.trim()).handle_error()?;
+    let description = transaction.read_description().handle_error()?;
+
+    Ok(ObjectBuilder::new()
+        .set("outMsgCount", transaction.outmsg_cnt)
+        .set("totalFees", transaction.total_fees.grams.0.to_string())
+        .set("lt", transaction.lt.to_string())
+        .set("now", transaction.now)
+        .set("aborted", description.is_aborted())
+        .build()
+        .unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "applyAnycast")]
+pub fn apply_anycast(address: &str, depth: u8, rewrite_pfx: u32) -> Result<String, JsValue> {
+    if !(1..=30).contains(&depth) {
+        return Err("Anycast depth must be in range 1..=30").handle_error();
+    }
+    if u64::from(rewrite_pfx) >> depth != 0 {
+        return Err("Rewrite prefix doesn't fit into anycast depth").handle_error();
+    }
+
+    let (workchain_id, mut account_id) = match parse_address(address)? {
+        ton_block::MsgAddressInt::AddrStd(address) => {
+            (address.workchain_id, address.address.get_bytestring(0))
+        }
+        ton_block::MsgAddressInt::AddrVar(_) => {
+            return Err("Anycast is only supported for standard addresses").handle_error()
+        }
+    };
+
+    // Replace the highest `depth` bits of the account id with the rewrite prefix
+    for i in 0..depth as usize {
+        let mask = 0x80 >> (i % 8);
+        if (rewrite_pfx >> (depth as usize - 1 - i)) & 1 == 1 {
+            account_id[i / 8] |= mask;
+        } else {
+            account_id[i / 8] &= !mask;
+        }
+    }
+
+    Ok(format!("{}:{}", workchain_id, hex::encode(account_id)))
+}
+
+#[wasm_bindgen(js_name = "getAccountStorageStats")]
+pub fn get_account_storage_stats(account_stuff_boc: &str) -> Result<AccountStorageStats, JsValue> {
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    Ok(make_account_storage_stats(&account_stuff.storage_stat))
+}
+
+#[wasm_bindgen(js_name = "decodeElectorStakes")]
+pub fn decode_elector_stakes(account_stuff_boc: &str) -> Result<ElectorStakesList, JsValue> {
+    use ton_types::HashmapType;
+
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    let mut data = ton_types::SliceData::from(get_account_data(&account_stuff)?.clone());
+
+    let stakes = js_sys::Array::new();
+
+    // Elector data starts with the optional current elections
+    if !data.get_next_bit().handle_error()? {
+        return Ok(stakes.unchecked_into());
+    }
+    let mut elections = ton_types::SliceData::from(data.checked_drain_reference().handle_error()?);
+
+    // Skip `elect_at`, `elect_close`, `min_stake` and `total_stake`
+    elections.move_by(64).handle_error()?;
+    ton_block::Grams::construct_from(&mut elections).handle_error()?;
+    ton_block::Grams::construct_from(&mut elections).handle_error()?;
+
+    let members = if elections.get_next_bit().handle_error()? {
+        Some(elections.checked_drain_reference().handle_error()?)
+    } else {
+        None
+    };
+
+    ton_types::HashmapE::with_hashmap(256, members)
+        .iterate_slices(|mut public_key, mut member| {
+            let public_key = public_key.get_next_bytes(32)?;
+            let stake = ton_block::Grams::construct_from(&mut member)?;
+            let time = member.get_next_u32()?;
+            let max_factor = member.get_next_u32()?;
+            let src_addr = member.get_next_bytes(32)?;
+            let adnl_addr = member.get_next_bytes(32)?;
+
+            stakes.push(
+                &ObjectBuilder::new()
+                    .set("publicKey", hex::encode(public_key))
+                    .set("stake", stake.0.to_string())
+                    .set("time", time)
+                    .set("maxFactor", max_factor)
+                    .set("srcAddr", format!("-1:{}", hex::encode(src_addr)))
+                    .set("adnlAddr", hex::encode(adnl_addr))
+                    .build(),
+            );
+            Ok(true)
+        })
+        .handle_error()?;
+
+    Ok(stakes.unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "decodeAllConfigParams")]
+pub fn decode_all_config_params(config_boc: &str) -> Result<DecodedConfigParams, JsValue> {
+    use ton_types::HashmapType;
+
+    let config = parse_config_params(config_boc)?;
+
+    let mut params = Vec::new();
+    config
+        .config_params
+        .iterate_slices(|mut key, value| {
+            params.push((key.get_next_u32()?, value.reference(0)?));
+            Ok(true)
+        })
+        .handle_error()?;
+
+    // Unknown or malformed params are returned as raw BOCs
+    let mut result = ObjectBuilder::new();
+    for (index, cell) in params {
+        let value = match config
+            .config(index)
+            .ok()
+            .flatten()
+            .and_then(make_config_param)
+        {
+            Some(value) => value,
+            None => JsValue::from(make_boc(&cell)?),
+        };
+        result = result.set(&index.to_string(), value);
+    }
+
+    Ok(result.build().unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "buildSimpleTransfer")]
+pub fn build_simple_transfer(transfer: SimpleTransfer) -> Result<Gift, JsValue> {
+    make_simple_transfer_gift(transfer)
+}
+
+#[wasm_bindgen(js_name = "decodeWalletV4Plugins")]
+pub fn decode_wallet_v4_plugins(account_stuff_boc: &str) -> Result<WalletV4PluginsList, JsValue> {
+    use ton_types::HashmapType;
+
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    let mut data = ton_types::SliceData::from(get_account_data(&account_stuff)?.clone());
+
+    // Wallet v4 data: `seqno`, `subwallet_id`, `public_key` and an optional plugins dict
+    if data.remaining_bits() != WALLET_V4_DATA_BITS || data.remaining_references() > 1 {
+        return Err("Invalid wallet v4 data").handle_error();
+    }
+
+    let plugins = data
+        .move_by(WALLET_V4_DATA_BITS - 1)
+        .and_then(|_| data.get_next_bit())
+        .and_then(|has_plugins| {
+            if has_plugins {
+                data.checked_drain_reference().map(Some)
+            } else {
+                Ok(None)
+            }
+        })
+        .map_err(|_| "Invalid wallet v4 data")
+        .handle_error()?;
+    if data.remaining_references() != 0 {
+        return Err("Invalid wallet v4 data").handle_error();
+    }
+
+    let result = js_sys::Array::new();
+    ton_types::HashmapE::with_hashmap(8 + 256, plugins)
+        .iterate_slices(|mut key, _| {
+            let workchain = key.get_next_byte()? as i8;
+            let address = key.get_next_bytes(32)?;
+
+            result.push(
+                &ObjectBuilder::new()
+                    .set("workchain", workchain)
+                    .set("address", format!("{}:{}", workchain, hex::encode(address)))
+                    .build(),
+            );
+            Ok(true)
+        })
+        .handle_error()?;
+
+    Ok(result.unchecked_into())
+}
+
+const WALLET_V4_DATA_BITS: usize = 32 + 32 + 256 + 1;
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "getFunctionSigningHash")]
+pub fn get_function_signing_hash(
+    clock: &ClockWithOffset,
+    contract_abi: &str,
+    method: &str,
+    input: TokensObject,
+    timeout: u32,
+    dst: Option<String>,
+    headers: Option<TokensObject>,
+    abi_version: Option<String>,
+) -> Result<String, JsValue> {
+    use nt::core::models::{Expiration, ExpireAt};
+
+    let contract_abi = parse_contract_abi(contract_abi)?;
+    let mut method = contract_abi.function(method).handle_error()?.clone();
+    if let Some(abi_version) = abi_version.as_deref() {
+        // Function id depends on the major version, so only minor overrides are allowed
+        let abi_version = parse_abi_version(abi_version)?;
+        if abi_version.major != method.abi_version.major {
+            return Err("ABI major version can't be overridden").handle_error();
+        }
+        method.abi_version = abi_version;
+    }
+    let input = parse_tokens_object(&method.inputs, input).handle_error()?;
+    let dst = dst.as_deref().map(parse_address).transpose()?;
+
+    // Fill missing headers with the same defaults as for unsigned messages
+    let time = clock.inner.now_ms_u64();
+    let expire_at = ExpireAt::new_from_millis(Expiration::Timeout(timeout), time);
+    let mut defaults = make_unsigned_header(time, expire_at.timestamp);
+
+    let headers = headers.map(JsValue::from).unwrap_or_default();
+    let mut header = HashMap::with_capacity(method.header.len());
+    for param in &method.header {
+        let value = if headers.is_object() {
+            js_sys::Reflect::get(&headers, &JsValue::from_str(&param.name))?
+        } else {
+            JsValue::undefined()
+        };
+
+        let value = if !value.is_undefined() {
+            parse_token_value(&param.kind, value).handle_error()?
+        } else {
+            defaults
+                .remove(&param.name)
+                .ok_or_else(|| TokensJsonError::ParameterNotFound(param.name.clone()))
+                .handle_error()?
+        };
+        header.insert(param.name.clone(), value);
+    }
+
+    let (_, hash) = method
+        .create_unsigned_call(&header, &input, false, true, dst)
+        .handle_error()?;
+    Ok(hex::encode(hash))
+}
+
+#[wasm_bindgen(js_name = "assembleExternalMessage")]
+pub fn assemble_external_message(
+    dst: &str,
+    signed_body: &str,
+    state_init: Option<String>,
+    expire_at: u32,
+) -> Result<SignedMessage, JsValue> {
+    let dst = parse_address(dst)?;
+    let body = parse_cell_slice(signed_body)?;
+    let state_init = state_init
+        .as_deref()
+        .map(ton_block::StateInit::construct_from_base64)
+        .transpose()
+        .handle_error()?;
+
+    let mut message =
+        ton_block::Message::with_ext_in_header(ton_block::ExternalInboundMessageHeader {
+            dst,
+            ..Default::default()
+        });
+    if let Some(state_init) = state_init {
+        message.set_state_init(state_init);
+    }
+    message.set_body(body);
+
+    make_signed_message(nt::crypto::SignedMessage { message, expire_at })
+}
+
+#[wasm_bindgen(js_name = "decodeTransactionBouncePhase")]
+pub fn decode_transaction_bounce_phase(
+    transaction: &str,
+) -> Result<TransactionBounceInfo, JsValue> {
+    let transaction =
+        ton_block::Transaction::construct_from_base64(transaction.trim()).handle_error()?;
+    let description = match transaction.read_description().handle_error()? {
+        ton_block::TransactionDescr::Ordinary(description) => description,
+        _ => return Err("Expected ordinary transaction").handle_error(),
+    };
+
+    Ok(ObjectBuilder::new()
+        .set("creditFirst", description.credit_first)
+        .set(
+            "bounce",
+            description
+                .bounce
+                .as_ref()
+                .map(make_transaction_bounce_phase)
+                .unwrap_or_default(),
+        )
+        .build()
+        .unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "computeQueryId")]
+pub fn compute_query_id(seed: &str) -> String {
+    use sha2::Digest;
+
+    let hash = sha2::Sha256::digest(seed.as_bytes());
+    let mut query_id = [0u8; 8];
+    query_id.copy_from_slice(&hash[..8]);
+    u64::from_be_bytes(query_id).to_string()
+}
+
+#[wasm_bindgen(js_name = "verifySignedCell")]
+pub fn verify_signed_cell(boc: &str, public_key: &str) -> Result<VerifiedSignedCell, JsValue> {
+    let public_key = parse_public_key(public_key)?;
+    let mut container = parse_cell_slice(boc)?;
+
+    // Signed cell layout: 512-bit signature with the data cell as the first reference
+    if container.remaining_bits() != SIGNED_CELL_SIGNATURE_BITS
+        || container.remaining_references() != 1
+    {
+        return Err("Invalid signed cell. Expected 512-bit signature and data reference")
+            .handle_error();
+    }
+    let signature = container
+        .get_next_bytes(SIGNED_CELL_SIGNATURE_BITS / 8)
+        .handle_error()?;
+    let signature = ed25519_dalek::Signature::try_from(signature.as_slice()).handle_error()?;
+    let data = container.checked_drain_reference().handle_error()?;
+
+    let valid = public_key
+        .verify(data.repr_hash().as_slice(), &signature)
+        .is_ok();
+
+    Ok(ObjectBuilder::new()
+        .set("valid", valid)
+        .set("data", make_boc(&data)?)
+        .build()
+        .unchecked_into())
+}
+
+const SIGNED_CELL_SIGNATURE_BITS: usize = 512;
+
+#[wasm_bindgen(js_name = "buildSignedCell")]
+pub fn build_signed_cell(data: &str, secret_key: &str) -> Result<String, JsValue> {
+    let data = parse_cell(data)?;
+    let key_pair = parse_key_pair(secret_key)?;
+    let signature = key_pair.sign(data.repr_hash().as_slice());
+
+    let mut builder = ton_types::BuilderData::new();
+    builder
+        .append_raw(&signature.to_bytes(), SIGNED_CELL_SIGNATURE_BITS)
+        .and_then(|builder| builder.checked_append_reference(data))
+        .handle_error()?;
+
+    make_boc(&builder.into_cell().handle_error()?)
+}
+
+#[wasm_bindgen(js_name = "estimateTimeUntilFrozen")]
+pub fn estimate_time_until_frozen(
+    clock: &ClockWithOffset,
+    account_stuff: &str,
+    config_boc: &str,
+) -> Result<FreezeEstimation, JsValue> {
+    let account_stuff = parse_account_stuff(account_stuff)?;
+    let config = ton_executor::BlockchainConfig::with_config(parse_config_params(config_boc)?)
+        .handle_error()?;
+    let now = clock.inner.now_sec_u64() as u32;
+
+    let seconds_remaining = match &account_stuff.storage.state {
+        ton_block::AccountState::AccountActive { .. } => {
+            let is_masterchain = account_stuff.addr.is_masterchain();
+            let freeze_due_limit = config.get_gas_config(is_masterchain).freeze_due_limit as u128;
+            let due_payment = account_stuff
+                .storage_stat
+                .due_payment()
+                .map(|due_payment| due_payment.0)
+                .unwrap_or_default();
+
+            // Storage fee is paid from the balance first and then accumulated as a debt
+            // until it exceeds the freeze limit
+            let budget = (account_stuff.storage.balance.grams.0 + freeze_due_limit)
+                .saturating_sub(due_payment);
+            let storage_fee = |time: u32| {
+                config.calc_storage_fee(&account_stuff.storage_stat, is_masterchain, time)
+            };
+
+            if storage_fee(u32::MAX) <= budget {
+                None
+            } else {
+                // Find the first moment when the accumulated fee exceeds the budget
+                let (mut lo, mut hi) = (now, u32::MAX);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if storage_fee(mid) > budget {
+                        hi = mid;
+                    } else {
+                        lo = mid + 1;
+                    }
+                }
+                Some(lo - now)
+            }
+        }
+        _ => None,
+    };
+
+    Ok(ObjectBuilder::new()
+        .set("secondsRemaining", seconds_remaining)
+        .set("willFreeze", seconds_remaining.is_some())
+        .set(
+            "frozen",
+            matches!(
+                account_stuff.storage.state,
+                ton_block::AccountState::AccountFrozen { .. }
+            ),
+        )
+        .build()
+        .unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "readSliceFields")]
+pub fn read_slice_fields(boc: &str, fields: SliceFieldsList) -> Result<SliceFieldValues, JsValue> {
+    let mut slice = parse_cell_slice(boc)?;
+    let fields = parse_slice_fields(fields)?;
+
+    let mut result = ObjectBuilder::new();
+    for field in fields {
+        if slice.remaining_bits() < field.bits {
+            return Err(format!("Not enough bits to read field `{}`", field.name)).handle_error();
+        }
+        let bytes = slice.get_next_bits(field.bits).handle_error()?;
+
+        // Bits are left-aligned in the last byte
+        let value = num_bigint::BigUint::from_bytes_be(&bytes) >> (bytes.len() * 8 - field.bits);
+        result = result.set(&field.name, make_slice_field_value(field.bits, &value));
+    }
+
+    Ok(result.build().unchecked_into())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "encodeInternalMessage")]
+pub fn encode_internal_message(
+    clock: &ClockWithOffset,
+    src: Option<String>,
+    dst: &str,
+    bounce: bool,
+    state_init: Option<String>,
+    body: Option<String>,
+    amount: &str,
+    created_lt: Option<String>,
+    created_at: Option<u32>,
+) -> Result<String, JsValue> {
+    let src = match src.as_deref() {
+        Some(src) => ton_block::MsgAddressIntOrNone::Some(parse_address(src)?),
+        None => ton_block::MsgAddressIntOrNone::None,
+    };
+    let dst = parse_address(dst)?;
+    let state_init = state_init
+        .as_deref()
+        .map(ton_block::StateInit::construct_from_base64)
+        .transpose()
+        .handle_error()?;
+    let body = body.as_deref().map(parse_cell_slice).transpose()?;
+    let amount = u64::from_str(amount.trim()).handle_error()?;
+    let created_lt = match created_lt.as_deref() {
+        Some(created_lt) => u64::from_str(created_lt.trim()).handle_error()?,
+        None => 0,
+    };
+    let created_at = created_at.unwrap_or_else(|| clock.inner.now_sec_u64() as u32);
+
+    let mut message = ton_block::Message::with_int_header(ton_block::InternalMessageHeader {
+        ihr_disabled: true,
+        bounce,
+        src,
+        dst,
+        value: ton_block::CurrencyCollection::with_grams(amount),
+        created_lt,
+        created_at: created_at.into(),
+        ..Default::default()
+    });
+    if let Some(state_init) = state_init {
+        message.set_state_init(state_init);
+    }
+    if let Some(body) = body {
+        message.set_body(body);
+    }
+
+    make_boc(&message.serialize().handle_error()?)
+}
+
+#[wasm_bindgen(js_name = "decodeJettonRootData")]
+pub fn decode_jetton_root_data(account_stuff: &str) -> Result<JettonRootData, JsValue> {
+    let account_stuff = parse_account_stuff(account_stuff)?;
+    let mut data = ton_types::SliceData::from(get_account_data(&account_stuff)?.clone());
+
+    // TEP-74 master data: total supply, admin address, content, wallet code
+    let (total_supply, admin, content, wallet_code) = (|| {
+        let total_supply = ton_block::Grams::construct_from(&mut data)?;
+        let admin = ton_block::MsgAddressIntOrNone::construct_from(&mut data)?;
+        let content = data.checked_drain_reference()?;
+        let wallet_code = data.checked_drain_reference()?;
+        Ok::<_, ton_types::Error>((total_supply, admin, content, wallet_code))
+    })()
+    .map_err(|e| format!("Account is not a jetton master: {}", e))
+    .handle_error()?;
+    if data.remaining_bits() != 0 || data.remaining_references() != 0 {
+        return Err("Account is not a jetton master: unexpected data after wallet code")
+            .handle_error();
+    }
+
+    let admin = match admin {
+        ton_block::MsgAddressIntOrNone::Some(admin) => Some(admin.to_string()),
+        ton_block::MsgAddressIntOrNone::None => None,
+    };
+
+    Ok(ObjectBuilder::new()
+        .set("totalSupply", total_supply.0.to_string())
+        // Minting requires an admin, so a minter with the admin dropped is not mintable
+        .set("mintable", admin.is_some())
+        .set("admin", admin)
+        .set("content", make_boc(&content)?)
+        .set("walletCode", make_boc(&wallet_code)?)
+        .build()
+        .unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "detectInterfaces")]
+pub fn detect_interfaces(
+    clock: &ClockWithOffset,
+    account_stuff_boc: &str,
+    contract_abi: Option<String>,
+) -> Result<StringArray, JsValue> {
+    use nt::core::ton_wallet::{self, highload_wallet_v2, multisig, wallet_v3};
+
+    let account_stuff = parse_account_stuff(account_stuff_boc)?;
+    let contract_abi = contract_abi
+        .as_deref()
+        .map(parse_contract_abi)
+        .transpose()?;
+
+    let result = js_sys::Array::new();
+
+    // Well-known contracts are detected by their code hash
+    if let ton_block::AccountState::AccountActive { state_init, .. } = &account_stuff.storage.state
+    {
+        if let Some(code) = &state_init.code {
+            let code_hash = code.repr_hash();
+            let wallet_type = if wallet_v3::is_wallet_v3(&code_hash) {
+                Some(ton_wallet::WalletType::WalletV3)
+            } else if highload_wallet_v2::is_highload_wallet_v2(&code_hash) {
+                Some(ton_wallet::WalletType::HighloadWalletV2)
+            } else {
+                multisig::guess_multisig_type(&code_hash).map(ton_wallet::WalletType::Multisig)
+            };
+
+            if let Some(wallet_type) = wallet_type {
+                result.push(&JsValue::from(wallet_type.to_string()));
+            }
+        }
+    }
+
+    // Other standards are detected with the TIP-6 `supportsInterface` getter.
+    // Its signature is fixed by the standard, so it is also available without an ABI
+    let tip6_abi = parse_contract_abi(TIP6_ABI)?;
+    let method = contract_abi
+        .as_ref()
+        .and_then(|contract_abi| contract_abi.function("supportsInterface").ok())
+        .or_else(|| tip6_abi.function("supportsInterface").ok());
+    if let Some(method) = method {
+        let responsible = matches!(method.inputs.first(), Some(param) if param.name == "answerId");
+
+        let supports_interface = |interface_id: u32| -> Result<bool, JsValue> {
+            let input = method
+                .inputs
+                .iter()
+                .map(|param| {
+                    let value = match &param.kind {
+                        ton_abi::ParamType::Uint(32) if param.name == "answerId" => 0,
+                        ton_abi::ParamType::Uint(32) => interface_id,
+                        _ => return Err("Unexpected supportsInterface signature").handle_error(),
+                    };
+                    Ok(ton_abi::Token::new(
+                        &param.name,
+                        ton_abi::TokenValue::Uint(ton_abi::Uint::new(value as u128, 32)),
+                    ))
+                })
+                .collect::<Result<Vec<_>, JsValue>>()?;
+
+            let output = if responsible {
+                method.run_local_responsible(clock.inner.as_ref(), account_stuff.clone(), &input)
+            } else {
+                method.run_local(clock.inner.as_ref(), account_stuff.clone(), &input)
+            };
+
+            // Contracts without the getter fail to execute it
+            Ok(match output {
+                Ok(output) => match output.tokens.as_deref() {
+                    Some([token, ..]) if output.result_code == 0 => {
+                        token.value == ton_abi::TokenValue::Bool(true)
+                    }
+                    _ => false,
+                },
+                Err(_) => false,
+            })
+        };
+
+        // Other interfaces are queried only if the contract implements TIP-6 itself
+        if supports_interface(TIP6_INTERFACE_ID)? {
+            result.push(&JsValue::from_str("TIP-6.1"));
+            for (interface_id, name) in KNOWN_INTERFACES {
+                if supports_interface(interface_id)? {
+                    result.push(&JsValue::from_str(name));
+                }
+            }
+        }
+    }
+
+    Ok(result.unchecked_into())
+}
+
+const TIP6_ABI: &str = r#"{
+    "ABI version": 2,
+    "version": "2.2",
+    "header": ["pubkey", "time", "expire"],
+    "functions": [
+        {
+            "name": "supportsInterface",
+            "inputs": [
+                { "name": "answerId", "type": "uint32" },
+                { "name": "interfaceID", "type": "uint32" }
+            ],
+            "outputs": [{ "name": "value0", "type": "bool" }]
+        }
+    ],
+    "events": [],
+    "data": [],
+    "fields": []
+}"#;
+
+const TIP6_INTERFACE_ID: u32 = 0x3204ec29;
+
+const KNOWN_INTERFACES: [(u32, &str); 7] = [
+    (0x4371d8ed, "TIP-3.1 TokenRoot"),
+    (0x4f479fa3, "TIP-3.1 TokenWallet"),
+    (0x1217aaab, "TIP-4.1 Collection"),
+    (0x78084f7d, "TIP-4.1 NFT"),
+    (0x24d7d5f5, "TIP-4.2"),
+    (0x4387bbfb, "TIP-4.3 Collection"),
+    (0x4df6250b, "TIP-4.3 NFT"),
+];
+
+#[wasm_bindgen(js_name = "verifyUpgradePreservesAddress")]
+pub fn verify_upgrade_preserves_address(
+    old_tvc: &str,
+    new_code: &str,
+    new_data: &str,
+) -> Result<bool, JsValue> {
+    let old_state_init = ton_block::StateInit::construct_from_base64(old_tvc).handle_error()?;
+    let new_state_init = ton_block::StateInit {
+        code: Some(parse_cell(new_code)?),
+        data: Some(parse_cell(new_data)?),
+        ..old_state_init.clone()
+    };
+
+    // Address is derived from the StateInit hash, so a fresh deployment of the new state
+    // lands at the same address only when code and data are unchanged. An in-place upgrade
+    // keeps the address anyway, so `false` means that the new state can't be redeployed
+    // at the old address, e.g. when code and data were swapped
+    let old_hash = old_state_init.serialize().handle_error()?.repr_hash();
+    let new_hash = new_state_init.serialize().handle_error()?.repr_hash();
+    Ok(old_hash == new_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LARGE_INPUT_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": ["pubkey", "time", "expire"],
+        "functions": [
+            {
+                "name": "store",
+                "inputs": [
+                    { "name": "a", "type": "uint256" },
+                    { "name": "b", "type": "uint256" },
+                    { "name": "c", "type": "uint256" },
+                    { "name": "d", "type": "uint256" },
+                    { "name": "e", "type": "uint256" }
+                ],
+                "outputs": []
+            }
+        ],
+        "events": [],
+        "data": [],
+        "fields": []
+    }"#;
+
+    #[test]
+    fn unsigned_message_with_large_input() {
+        let contract_abi = ton_abi::Contract::load(LARGE_INPUT_ABI).unwrap();
+        let method = contract_abi.function("store").unwrap();
+        let input = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(|name| {
+                ton_abi::Token::new(
+                    name,
+                    ton_abi::TokenValue::Uint(ton_abi::Uint::new(u128::MAX, 256)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let dst =
+            parse_address("0:0000000000000000000000000000000000000000000000000000000000000000")
+                .unwrap();
+
+        let message =
+            make_unsigned_external_message(1_000_000, 1_000, dst, method, None, &input).unwrap();
+
+        // Headers, function id and 1280 bits of arguments don't fit into a single cell
+        let body = message.body().unwrap().into_cell();
+        assert!(body.bit_length() <= 1023);
+        assert_eq!(body.references_count(), 1);
+
+        // Returned hash must be the hash of the root cell
+        let (boc, hash) = serialize_message(&message).unwrap();
+        let cell = parse_cell(&boc).unwrap();
+        assert_eq!(cell.repr_hash(), hash);
+        assert_eq!(
+            ton_block::Message::construct_from_cell(cell)
+                .unwrap()
+                .body()
+                .unwrap()
+                .into_cell()
+                .repr_hash(),
+            body.repr_hash()
+        );
+    }
+}
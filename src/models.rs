@@ -418,8 +418,8 @@ type TokensObject = { [K in string]: AbiToken };
 
 #[wasm_bindgen(typescript_custom_section)]
 const PARAM: &str = r#"
-export type AbiParamKindUint = 'uint8' | 'uint16' | 'uint32' | 'uint64' | 'uint128' | 'uint160' | 'uint256';
-export type AbiParamKindInt = 'int8' | 'int16' | 'int32' | 'int64' | 'int128' | 'int160' | 'int256';
+export type AbiParamKindUint = `uint${number}`;
+export type AbiParamKindInt = `int${number}`;
 export type AbiParamKindTuple = 'tuple';
 export type AbiParamKindBool = 'bool';
 export type AbiParamKindCell = 'cell';
@@ -531,13 +531,7 @@ export type SignedMessage = {
 "#;
 
 pub fn make_signed_message(data: nt::crypto::SignedMessage) -> Result<SignedMessage, JsValue> {
-    let (boc, hash) = {
-        let cell = data.message.write_to_new_cell().handle_error()?.into();
-        (
-            base64::encode(ton_types::serialize_toc(&cell).handle_error()?),
-            cell.repr_hash(),
-        )
-    };
+    let (boc, hash) = serialize_message(&data.message)?;
 
     Ok(ObjectBuilder::new()
         .set("hash", hash.to_hex_string())
@@ -547,6 +541,16 @@ pub fn make_signed_message(data: nt::crypto::SignedMessage) -> Result<SignedMess
         .unchecked_into())
 }
 
+pub fn serialize_message(
+    message: &ton_block::Message,
+) -> Result<(String, ton_types::UInt256), JsValue> {
+    let cell = message.write_to_new_cell().handle_error()?.into();
+    Ok((
+        base64::encode(ton_types::serialize_toc(&cell).handle_error()?),
+        cell.repr_hash(),
+    ))
+}
+
 pub fn parse_signed_message(data: SignedMessage) -> Result<nt::crypto::SignedMessage, JsValue> {
     if !data.is_object() {
         return Err(TokensJsonError::ObjectExpected).handle_error();
@@ -605,6 +609,15 @@ pub fn make_ed25519_key_pair(data: ed25519_dalek::Keypair) -> Ed25519KeyPair {
         .unchecked_into()
 }
 
+#[wasm_bindgen(typescript_custom_section)]
+const SIGNING_TEST_VECTOR: &str = r#"
+export type SigningTestVector = {
+    publicKey: string,
+    dataHash: string,
+    signature: string,
+};
+"#;
+
 #[wasm_bindgen(typescript_custom_section)]
 const EXTENDED_SIGNATURE: &str = r#"
 export type ExtendedSignature = {
@@ -764,6 +777,616 @@ pub fn parse_gift(gift: Gift) -> Result<nt::core::ton_wallet::Gift, JsValue> {
     })
 }
 
+#[wasm_bindgen(typescript_custom_section)]
+const VALIDATOR_SET: &str = r#"
+export type ValidatorSetKind = 'previous' | 'current' | 'next';
+
+export type ValidatorDescription = {
+    publicKey: string,
+    weight: string,
+    adnlAddr?: string,
+};
+
+export type ValidatorSet = {
+    utimeSince: number,
+    utimeUntil: number,
+    total: number,
+    main: number,
+    totalWeight: string,
+    validators: ValidatorDescription[],
+};
+"#;
+
+pub fn parse_validator_set_kind(kind: ValidatorSetKind) -> Result<u32, JsValue> {
+    match JsValue::from(kind).as_string().as_deref() {
+        Some("previous") => Ok(32),
+        Some("current") => Ok(34),
+        Some("next") => Ok(36),
+        _ => Err("Expected 'previous', 'current' or 'next'").handle_error(),
+    }
+}
+
+pub fn make_validator_set(data: &ton_block::ValidatorSet) -> ValidatorSet {
+    ObjectBuilder::new()
+        .set("utimeSince", data.utime_since())
+        .set("utimeUntil", data.utime_until())
+        .set("total", data.total())
+        .set("main", data.main())
+        .set("totalWeight", data.total_weight().to_string())
+        .set(
+            "validators",
+            data.list()
+                .iter()
+                .map(|validator| {
+                    ObjectBuilder::new()
+                        .set("publicKey", hex::encode(validator.public_key.as_slice()))
+                        .set("weight", validator.weight.to_string())
+                        .set(
+                            "adnlAddr",
+                            validator
+                                .adnl_addr
+                                .as_ref()
+                                .map(ton_types::UInt256::to_hex_string),
+                        )
+                        .build()
+                })
+                .collect::<js_sys::Array>(),
+        )
+        .build()
+        .unchecked_into()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const DECODED_CONFIG_PARAMS: &str = r#"
+export type DecodedConfigParams = { [paramId: string]: string | object };
+"#;
+
+pub fn make_config_param(param: ton_block::ConfigParamEnum) -> Option<JsValue> {
+    use ton_block::ConfigParamEnum;
+
+    let make_address =
+        |address: &ton_types::UInt256| JsValue::from(format!("-1:{}", address.to_hex_string()));
+
+    Some(match param {
+        ConfigParamEnum::ConfigParam0(param) => make_address(&param.config_addr),
+        ConfigParamEnum::ConfigParam1(param) => make_address(&param.elector_addr),
+        ConfigParamEnum::ConfigParam2(param) => make_address(&param.minter_addr),
+        ConfigParamEnum::ConfigParam3(param) => make_address(&param.fee_collector_addr),
+        ConfigParamEnum::ConfigParam8(param) => ObjectBuilder::new()
+            .set("version", param.global_version.version)
+            .set(
+                "capabilities",
+                param.global_version.capabilities.to_string(),
+            )
+            .build(),
+        ConfigParamEnum::ConfigParam12(param) => {
+            let workchains = js_sys::Array::new();
+            param
+                .workchains
+                .iterate_with_keys(|workchain: i32, descr| {
+                    workchains.push(
+                        &ObjectBuilder::new()
+                            .set("workchain", workchain)
+                            .set("enabledSince", descr.enabled_since)
+                            .set("active", descr.active)
+                            .set("acceptMsgs", descr.accept_msgs)
+                            .set("basic", descr.basic)
+                            .set("version", descr.version)
+                            .set(
+                                "zerostateRootHash",
+                                descr.zerostate_root_hash.to_hex_string(),
+                            )
+                            .set(
+                                "zerostateFileHash",
+                                descr.zerostate_file_hash.to_hex_string(),
+                            )
+                            .build(),
+                    );
+                    Ok(true)
+                })
+                .ok()?;
+            workchains.into()
+        }
+        ConfigParamEnum::ConfigParam14(param) => ObjectBuilder::new()
+            .set(
+                "masterchainBlockFee",
+                param.masterchain_block_fee.0.to_string(),
+            )
+            .set("basechainBlockFee", param.basechain_block_fee.0.to_string())
+            .build(),
+        ConfigParamEnum::ConfigParam15(param) => ObjectBuilder::new()
+            .set("validatorsElectedFor", param.validators_elected_for)
+            .set("electionsStartBefore", param.elections_start_before)
+            .set("electionsEndBefore", param.elections_end_before)
+            .set("stakeHeldFor", param.stake_held_for)
+            .build(),
+        ConfigParamEnum::ConfigParam17(param) => ObjectBuilder::new()
+            .set("minStake", param.min_stake.0.to_string())
+            .set("maxStake", param.max_stake.0.to_string())
+            .set("minTotalStake", param.min_total_stake.0.to_string())
+            .set("maxStakeFactor", param.max_stake_factor)
+            .build(),
+        ConfigParamEnum::ConfigParam18(param) => {
+            let prices = js_sys::Array::new();
+            param
+                .map
+                .iterate_with_keys(|_: u32, item| {
+                    prices.push(
+                        &ObjectBuilder::new()
+                            .set("utimeSince", item.utime_since)
+                            .set("bitPricePs", item.bit_price_ps.to_string())
+                            .set("cellPricePs", item.cell_price_ps.to_string())
+                            .set("mcBitPricePs", item.mc_bit_price_ps.to_string())
+                            .set("mcCellPricePs", item.mc_cell_price_ps.to_string())
+                            .build(),
+                    );
+                    Ok(true)
+                })
+                .ok()?;
+            prices.into()
+        }
+        ConfigParamEnum::ConfigParam20(param) | ConfigParamEnum::ConfigParam21(param) => {
+            make_gas_limits_prices(&param)
+        }
+        ConfigParamEnum::ConfigParam24(param) | ConfigParamEnum::ConfigParam25(param) => {
+            make_msg_forward_prices(&param)
+        }
+        ConfigParamEnum::ConfigParam31(param) => {
+            let addresses = js_sys::Array::new();
+            param
+                .fundamental_smc_addr
+                .iterate_with_keys(|address: ton_types::UInt256, _: ()| {
+                    addresses.push(&make_address(&address));
+                    Ok(true)
+                })
+                .ok()?;
+            addresses.into()
+        }
+        ConfigParamEnum::ConfigParam32(param) => make_validator_set(&param.prev_validators).into(),
+        ConfigParamEnum::ConfigParam33(param) => {
+            make_validator_set(&param.prev_temp_validators).into()
+        }
+        ConfigParamEnum::ConfigParam34(param) => make_validator_set(&param.cur_validators).into(),
+        ConfigParamEnum::ConfigParam35(param) => {
+            make_validator_set(&param.cur_temp_validators).into()
+        }
+        ConfigParamEnum::ConfigParam36(param) => make_validator_set(&param.next_validators).into(),
+        ConfigParamEnum::ConfigParam37(param) => {
+            make_validator_set(&param.next_temp_validators).into()
+        }
+        _ => return None,
+    })
+}
+
+fn make_gas_limits_prices(data: &ton_block::GasLimitsPrices) -> JsValue {
+    ObjectBuilder::new()
+        .set("gasPrice", data.gas_price.to_string())
+        .set("gasLimit", data.gas_limit.to_string())
+        .set("specialGasLimit", data.special_gas_limit.to_string())
+        .set("gasCredit", data.gas_credit.to_string())
+        .set("blockGasLimit", data.block_gas_limit.to_string())
+        .set("freezeDueLimit", data.freeze_due_limit.to_string())
+        .set("deleteDueLimit", data.delete_due_limit.to_string())
+        .set("flatGasLimit", data.flat_gas_limit.to_string())
+        .set("flatGasPrice", data.flat_gas_price.to_string())
+        .build()
+}
+
+fn make_msg_forward_prices(data: &ton_block::MsgForwardPrices) -> JsValue {
+    ObjectBuilder::new()
+        .set("lumpPrice", data.lump_price.to_string())
+        .set("bitPrice", data.bit_price.to_string())
+        .set("cellPrice", data.cell_price.to_string())
+        .set("ihrPriceFactor", data.ihr_price_factor)
+        .set("firstFrac", data.first_frac)
+        .set("nextFrac", data.next_frac)
+        .build()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const BLOCK_ID: &str = r#"
+export type BlockId = {
+    workchain: number,
+    shard: string,
+    seqno: number,
+    rootHash: string,
+    fileHash: string,
+};
+"#;
+
+pub fn make_block_id(data: &ton_block::BlockIdExt) -> BlockId {
+    ObjectBuilder::new()
+        .set("workchain", data.shard_id.workchain_id())
+        .set(
+            "shard",
+            format!("{:016x}", data.shard_id.shard_prefix_with_tag()),
+        )
+        .set("seqno", data.seq_no)
+        .set("rootHash", data.root_hash.to_hex_string())
+        .set("fileHash", data.file_hash.to_hex_string())
+        .build()
+        .unchecked_into()
+}
+
+pub fn parse_block_id(data: BlockId) -> Result<ton_block::BlockIdExt, JsValue> {
+    #[derive(Deserialize)]
+    struct ParsedBlockId {
+        workchain: i32,
+        shard: String,
+        seqno: u32,
+        #[serde(rename = "rootHash")]
+        root_hash: String,
+        #[serde(rename = "fileHash")]
+        file_hash: String,
+    }
+
+    let parsed: ParsedBlockId = data.obj.into_serde().handle_error()?;
+    make_block_id_ext(
+        parsed.workchain,
+        &parsed.shard,
+        parsed.seqno,
+        &parsed.root_hash,
+        &parsed.file_hash,
+    )
+    .handle_error()
+}
+
+pub fn parse_block_id_str(data: &str) -> Result<ton_block::BlockIdExt, BlockIdError> {
+    let (short_id, hashes) = data
+        .trim()
+        .strip_prefix('(')
+        .and_then(|data| data.split_once("):"))
+        .ok_or(BlockIdError::InvalidFormat)?;
+
+    let mut parts = short_id.split(',');
+    let (workchain, shard, seqno) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(workchain), Some(shard), Some(seqno), None) => (workchain, shard, seqno),
+        _ => return Err(BlockIdError::InvalidFormat),
+    };
+    let (root_hash, file_hash) = hashes.split_once(':').ok_or(BlockIdError::InvalidFormat)?;
+
+    let workchain = i32::from_str(workchain.trim())
+        .map_err(|_| BlockIdError::InvalidWorkchain(workchain.to_owned()))?;
+    let seqno =
+        u32::from_str(seqno.trim()).map_err(|_| BlockIdError::InvalidSeqno(seqno.to_owned()))?;
+
+    make_block_id_ext(workchain, shard, seqno, root_hash, file_hash)
+}
+
+pub fn block_id_to_string(data: &ton_block::BlockIdExt) -> String {
+    format!(
+        "({},{:016X},{}):{}:{}",
+        data.shard_id.workchain_id(),
+        data.shard_id.shard_prefix_with_tag(),
+        data.seq_no,
+        data.root_hash.to_hex_string().to_uppercase(),
+        data.file_hash.to_hex_string().to_uppercase(),
+    )
+}
+
+fn make_block_id_ext(
+    workchain: i32,
+    shard: &str,
+    seqno: u32,
+    root_hash: &str,
+    file_hash: &str,
+) -> Result<ton_block::BlockIdExt, BlockIdError> {
+    let shard_id = u64::from_str_radix(shard.trim(), 16)
+        .ok()
+        .and_then(|shard| ton_block::ShardIdent::with_tagged_prefix(workchain, shard).ok())
+        .ok_or_else(|| BlockIdError::InvalidShard(shard.to_owned()))?;
+    let root_hash = ton_types::UInt256::from_str(root_hash.trim())
+        .map_err(|_| BlockIdError::InvalidRootHash(root_hash.to_owned()))?;
+    let file_hash = ton_types::UInt256::from_str(file_hash.trim())
+        .map_err(|_| BlockIdError::InvalidFileHash(file_hash.to_owned()))?;
+
+    Ok(ton_block::BlockIdExt {
+        shard_id,
+        seq_no: seqno,
+        root_hash,
+        file_hash,
+    })
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BlockIdError {
+    #[error("Invalid block id format. Expected `(workchain,shard,seqno):rootHash:fileHash`")]
+    InvalidFormat,
+    #[error("Invalid workchain: {}", .0)]
+    InvalidWorkchain(String),
+    #[error("Invalid shard: {}", .0)]
+    InvalidShard(String),
+    #[error("Invalid seqno: {}", .0)]
+    InvalidSeqno(String),
+    #[error("Invalid root hash: {}", .0)]
+    InvalidRootHash(String),
+    #[error("Invalid file hash: {}", .0)]
+    InvalidFileHash(String),
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const JETTON_TRANSFER: &str = r#"
+export type JettonTransfer = {
+    queryId: string,
+    amount: string,
+    destination: string,
+    responseDestination?: string,
+    customPayload?: string,
+    forwardAmount: string,
+    forwardPayload?: string,
+};
+"#;
+
+pub struct ParsedJettonTransfer {
+    pub query_id: u64,
+    pub amount: ton_block::Grams,
+    pub destination: ton_block::MsgAddressInt,
+    pub response_destination: Option<ton_block::MsgAddressInt>,
+    pub custom_payload: Option<ton_types::Cell>,
+    pub forward_amount: ton_block::Grams,
+    pub forward_payload: Option<ton_types::Cell>,
+}
+
+pub fn parse_jetton_transfer(data: JettonTransfer) -> Result<ParsedJettonTransfer, JsValue> {
+    #[derive(Deserialize)]
+    struct RawJettonTransfer {
+        #[serde(rename = "queryId", with = "nt::utils::serde_u64")]
+        query_id: u64,
+        amount: String,
+        #[serde(with = "nt::utils::serde_address")]
+        destination: ton_block::MsgAddressInt,
+        #[serde(rename = "responseDestination")]
+        response_destination: Option<String>,
+        #[serde(rename = "customPayload")]
+        custom_payload: Option<String>,
+        #[serde(rename = "forwardAmount")]
+        forward_amount: String,
+        #[serde(rename = "forwardPayload")]
+        forward_payload: Option<String>,
+    }
+
+    fn parse_grams(value: &str) -> Result<ton_block::Grams, JsValue> {
+        u128::from_str(value.trim())
+            .map(ton_block::Grams)
+            .map_err(|_| TokensJsonError::InvalidNumber(value.to_owned()))
+            .handle_error()
+    }
+
+    let parsed: RawJettonTransfer = data.obj.into_serde().handle_error()?;
+
+    Ok(ParsedJettonTransfer {
+        query_id: parsed.query_id,
+        amount: parse_grams(&parsed.amount)?,
+        destination: parsed.destination,
+        response_destination: parsed
+            .response_destination
+            .as_deref()
+            .map(parse_address)
+            .transpose()?,
+        custom_payload: parsed
+            .custom_payload
+            .as_deref()
+            .map(parse_cell)
+            .transpose()?,
+        forward_amount: parse_grams(&parsed.forward_amount)?,
+        forward_payload: parsed
+            .forward_payload
+            .as_deref()
+            .map(parse_cell)
+            .transpose()?,
+    })
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TRANSACTION_STATS: &str = r#"
+export type TransactionStats = {
+    outMsgCount: number,
+    totalFees: string,
+    lt: string,
+    now: number,
+    aborted: boolean,
+};
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const ACCOUNT_STORAGE_STATS: &str = r#"
+export type AccountStorageStats = {
+    cells: string,
+    bits: string,
+    publicCells: string,
+    lastPaid: number,
+    duePayment?: string,
+};
+"#;
+
+pub fn make_account_storage_stats(data: &ton_block::StorageInfo) -> AccountStorageStats {
+    let used = data.used();
+    ObjectBuilder::new()
+        .set("cells", used.cells().to_string())
+        .set("bits", used.bits().to_string())
+        .set("publicCells", used.public_cells().to_string())
+        .set("lastPaid", data.last_paid())
+        .set(
+            "duePayment",
+            data.due_payment().map(|grams| grams.0.to_string()),
+        )
+        .build()
+        .unchecked_into()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const ELECTOR_STAKE: &str = r#"
+export type ElectorStake = {
+    publicKey: string,
+    stake: string,
+    time: number,
+    maxFactor: number,
+    srcAddr: string,
+    adnlAddr: string,
+};
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const SIMPLE_TRANSFER: &'static str = r#"
+export type SimpleTransfer = {
+    dst: string;
+    amount: string;
+    bounce: boolean;
+    comment?: string;
+    stateInit?: string;
+};
+"#;
+
+pub fn make_simple_transfer_gift(transfer: SimpleTransfer) -> Result<Gift, JsValue> {
+    #[derive(Deserialize)]
+    struct ParsedSimpleTransfer {
+        #[serde(with = "nt::utils::serde_address")]
+        dst: ton_block::MsgAddressInt,
+        #[serde(with = "nt::utils::serde_u64")]
+        amount: u64,
+        bounce: bool,
+        comment: Option<String>,
+        #[serde(rename = "stateInit")]
+        state_init: Option<String>,
+    }
+
+    let parsed: ParsedSimpleTransfer = transfer.obj.into_serde().handle_error()?;
+    let body = match parsed.comment.as_deref() {
+        Some(comment) if !comment.is_empty() => Some(
+            nt::abi::create_comment_payload(comment)
+                .handle_error()
+                .and_then(|body| make_boc(&body.into_cell()))?,
+        ),
+        _ => None,
+    };
+    if let Some(state_init) = &parsed.state_init {
+        ton_block::StateInit::construct_from_base64(state_init).handle_error()?;
+    }
+
+    Ok(ObjectBuilder::new()
+        .set("flags", SIMPLE_TRANSFER_FLAGS)
+        .set("bounce", parsed.bounce)
+        .set("destination", parsed.dst.to_string())
+        .set("amount", parsed.amount.to_string())
+        .set("body", body)
+        .set("stateInit", parsed.state_init)
+        .build()
+        .unchecked_into())
+}
+
+const SIMPLE_TRANSFER_FLAGS: u8 = 3;
+
+#[wasm_bindgen(typescript_custom_section)]
+const WALLET_V4_PLUGIN: &str = r#"
+export type WalletV4Plugin = {
+    workchain: number,
+    address: string,
+};
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TRANSACTION_BOUNCE_PHASE: &str = r#"
+export type TransactionBouncePhase =
+    | EnumItem<'ok', { msgFees: string, fwdFees: string }>
+    | EnumItem<'noFunds', { reqFwdFees: string }>
+    | EnumItem<'negFunds', undefined>;
+
+export type TransactionBounceInfo = {
+    creditFirst: boolean,
+    bounce?: TransactionBouncePhase,
+};
+"#;
+
+pub fn make_transaction_bounce_phase(phase: &ton_block::TrBouncePhase) -> JsValue {
+    let (ty, data) = match phase {
+        ton_block::TrBouncePhase::Ok(phase) => (
+            "ok",
+            ObjectBuilder::new()
+                .set("msgFees", phase.msg_fees.0.to_string())
+                .set("fwdFees", phase.fwd_fees.0.to_string())
+                .build(),
+        ),
+        ton_block::TrBouncePhase::Nofunds(phase) => (
+            "noFunds",
+            ObjectBuilder::new()
+                .set("reqFwdFees", phase.req_fwd_fees.0.to_string())
+                .build(),
+        ),
+        ton_block::TrBouncePhase::Negfunds => ("negFunds", JsValue::undefined()),
+    };
+
+    ObjectBuilder::new()
+        .set("type", ty)
+        .set("data", data)
+        .build()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const VERIFIED_SIGNED_CELL: &str = r#"
+export type VerifiedSignedCell = {
+    valid: boolean,
+    data: string,
+};
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const FREEZE_ESTIMATION: &str = r#"
+export type FreezeEstimation = {
+    secondsRemaining?: number,
+    willFreeze: boolean,
+    frozen: boolean,
+};
+"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const SLICE_FIELD: &str = r#"
+export type SliceField = {
+    name: string,
+    bits: number,
+};
+
+export type SliceFieldValue = {
+    hex: string,
+    decimal: string,
+};
+"#;
+
+#[derive(Deserialize)]
+pub struct ParsedSliceField {
+    pub name: String,
+    pub bits: usize,
+}
+
+pub fn parse_slice_fields(fields: SliceFieldsList) -> Result<Vec<ParsedSliceField>, JsValue> {
+    JsValue::from(fields).into_serde().handle_error()
+}
+
+pub fn make_slice_field_value(bits: usize, value: &num_bigint::BigUint) -> JsValue {
+    ObjectBuilder::new()
+        .set(
+            "hex",
+            format!(
+                "{:0>width$}",
+                value.to_str_radix(16),
+                width = (bits + 3) / 4
+            ),
+        )
+        .set("decimal", value.to_string())
+        .build()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const JETTON_ROOT_DATA: &str = r#"
+export type JettonRootData = {
+    totalSupply: string,
+    /** Derived from the admin presence, not stored in the master data */
+    mintable: boolean,
+    admin?: string,
+    content: string,
+    walletCode: string,
+};
+"#;
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(typescript_type = "TransactionId")]
@@ -862,6 +1485,9 @@ extern "C" {
     #[wasm_bindgen(typescript_type = "Promise<FullContractState | undefined>")]
     pub type PromiseOptionFullContractState;
 
+    #[wasm_bindgen(typescript_type = "Promise<Array<FullContractState | undefined | null>>")]
+    pub type PromiseFullContractStatesList;
+
     #[wasm_bindgen(typescript_type = "Ed25519KeyPair")]
     pub type Ed25519KeyPair;
 
@@ -876,4 +1502,55 @@ extern "C" {
 
     #[wasm_bindgen(typescript_type = "Array<Gift>")]
     pub type GiftList;
+
+    #[wasm_bindgen(typescript_type = "ValidatorSetKind")]
+    pub type ValidatorSetKind;
+
+    #[wasm_bindgen(typescript_type = "ValidatorSet")]
+    pub type ValidatorSet;
+
+    #[wasm_bindgen(typescript_type = "DecodedConfigParams")]
+    pub type DecodedConfigParams;
+
+    #[wasm_bindgen(typescript_type = "BlockId")]
+    pub type BlockId;
+
+    #[wasm_bindgen(typescript_type = "JettonTransfer")]
+    pub type JettonTransfer;
+
+    #[wasm_bindgen(typescript_type = "TransactionStats")]
+    pub type TransactionStats;
+
+    #[wasm_bindgen(typescript_type = "AccountStorageStats")]
+    pub type AccountStorageStats;
+
+    #[wasm_bindgen(typescript_type = "Array<ElectorStake>")]
+    pub type ElectorStakesList;
+
+    #[wasm_bindgen(typescript_type = "SigningTestVector")]
+    pub type SigningTestVector;
+
+    #[wasm_bindgen(typescript_type = "SimpleTransfer")]
+    pub type SimpleTransfer;
+
+    #[wasm_bindgen(typescript_type = "Array<WalletV4Plugin>")]
+    pub type WalletV4PluginsList;
+
+    #[wasm_bindgen(typescript_type = "TransactionBounceInfo")]
+    pub type TransactionBounceInfo;
+
+    #[wasm_bindgen(typescript_type = "VerifiedSignedCell")]
+    pub type VerifiedSignedCell;
+
+    #[wasm_bindgen(typescript_type = "FreezeEstimation")]
+    pub type FreezeEstimation;
+
+    #[wasm_bindgen(typescript_type = "Array<SliceField>")]
+    pub type SliceFieldsList;
+
+    #[wasm_bindgen(typescript_type = "Record<string, SliceFieldValue>")]
+    pub type SliceFieldValues;
+
+    #[wasm_bindgen(typescript_type = "JettonRootData")]
+    pub type JettonRootData;
 }
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use std::str::FromStr;
 
 use nt::utils::*;
-use num_bigint::{BigInt, BigUint};
+use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::Num;
 use ton_block::Serializable;
 use wasm_bindgen::{JsCast, JsValue};
@@ -183,6 +183,9 @@ pub fn parse_token_value(
 
             match param {
                 ton_abi::ParamType::Uint(_) => {
+                    if !unsigned_number_fits(&number, size) {
+                        return Err(TokensJsonError::NumberOutOfRange(size, number.to_string()));
+                    }
                     ton_abi::TokenValue::Uint(ton_abi::Uint { number, size })
                 }
                 _ => ton_abi::TokenValue::VarUint(size, number),
@@ -211,6 +214,9 @@ pub fn parse_token_value(
 
             match param {
                 ton_abi::ParamType::Int(_) => {
+                    if !signed_number_fits(&number, size) {
+                        return Err(TokensJsonError::NumberOutOfRange(size, number.to_string()));
+                    }
                     ton_abi::TokenValue::Int(ton_abi::Int { number, size })
                 }
                 _ => ton_abi::TokenValue::VarInt(size, number),
@@ -521,14 +527,8 @@ pub fn parse_param_type(kind: &str) -> Result<ton_abi::ParamType, TokensJsonErro
     let result = match kind {
         "bool" => ton_abi::ParamType::Bool,
         "tuple" => ton_abi::ParamType::Tuple(Vec::new()),
-        s if s.starts_with("int") => {
-            let len = usize::from_str(&s[3..]).map_err(|_| TokensJsonError::ParamTypeExpected)?;
-            ton_abi::ParamType::Int(len)
-        }
-        s if s.starts_with("uint") => {
-            let len = usize::from_str(&s[4..]).map_err(|_| TokensJsonError::ParamTypeExpected)?;
-            ton_abi::ParamType::Uint(len)
-        }
+        s if s.starts_with("int") => ton_abi::ParamType::Int(parse_integer_size(&s[3..])?),
+        s if s.starts_with("uint") => ton_abi::ParamType::Uint(parse_integer_size(&s[4..])?),
         s if s.starts_with("varint") => {
             let len = usize::from_str(&s[6..]).map_err(|_| TokensJsonError::ParamTypeExpected)?;
             ton_abi::ParamType::Int(len)
@@ -580,3 +580,82 @@ pub fn parse_param_type(kind: &str) -> Result<ton_abi::ParamType, TokensJsonErro
 
     Ok(result)
 }
+
+fn parse_integer_size(size: &str) -> Result<usize, TokensJsonError> {
+    match usize::from_str(size) {
+        Ok(size) if (1..=256).contains(&size) => Ok(size),
+        _ => Err(TokensJsonError::ParamTypeExpected),
+    }
+}
+
+fn unsigned_number_fits(number: &BigUint, size: usize) -> bool {
+    number.bits() <= size as u64
+}
+
+fn signed_number_fits(number: &BigInt, size: usize) -> bool {
+    let magnitude = match number.sign() {
+        Sign::Minus => -number - BigInt::from(1),
+        _ => number.clone(),
+    };
+    magnitude.bits() < size as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_non_standard_integer_widths() {
+        assert_eq!(
+            parse_param_type("uint5").unwrap(),
+            ton_abi::ParamType::Uint(5)
+        );
+        assert_eq!(
+            parse_param_type("int24").unwrap(),
+            ton_abi::ParamType::Int(24)
+        );
+        assert_eq!(
+            parse_param_type("uint200").unwrap(),
+            ton_abi::ParamType::Uint(200)
+        );
+        assert_eq!(
+            parse_param_type("int1").unwrap(),
+            ton_abi::ParamType::Int(1)
+        );
+        assert_eq!(
+            parse_param_type("uint256").unwrap(),
+            ton_abi::ParamType::Uint(256)
+        );
+
+        assert!(parse_param_type("uint0").is_err());
+        assert!(parse_param_type("int0").is_err());
+        assert!(parse_param_type("uint257").is_err());
+        assert!(parse_param_type("int257").is_err());
+    }
+
+    #[test]
+    fn checks_unsigned_ranges() {
+        assert!(unsigned_number_fits(&BigUint::from(0u32), 5));
+        assert!(unsigned_number_fits(&BigUint::from(31u32), 5));
+        assert!(!unsigned_number_fits(&BigUint::from(32u32), 5));
+
+        let max_uint200 = (BigUint::from(1u32) << 200) - BigUint::from(1u32);
+        assert!(unsigned_number_fits(&max_uint200, 200));
+        assert!(!unsigned_number_fits(
+            &(max_uint200 + BigUint::from(1u32)),
+            200
+        ));
+    }
+
+    #[test]
+    fn checks_signed_ranges() {
+        let min_int24 = -(BigInt::from(1) << 23);
+        let max_int24 = (BigInt::from(1) << 23) - BigInt::from(1);
+
+        assert!(signed_number_fits(&min_int24, 24));
+        assert!(signed_number_fits(&max_int24, 24));
+        assert!(signed_number_fits(&BigInt::from(0), 24));
+        assert!(!signed_number_fits(&(min_int24 - BigInt::from(1)), 24));
+        assert!(!signed_number_fits(&(max_int24 + BigInt::from(1)), 24));
+    }
+}
@@ -119,6 +119,46 @@ impl Transport {
         })))
     }
 
+    #[wasm_bindgen(js_name = "getFullContractStates")]
+    pub fn get_full_account_states(
+        &self,
+        addresses: StringArray,
+    ) -> Result<PromiseFullContractStatesList, JsValue> {
+        if !js_sys::Array::is_array(&addresses) {
+            return Err(TokensJsonError::ArrayExpected).handle_error();
+        }
+        let addresses = addresses
+            .unchecked_into::<js_sys::Array>()
+            .iter()
+            .map(|address| match address.as_string() {
+                Some(address) => parse_address(&address),
+                None => Err(TokensJsonError::StringExpected).handle_error(),
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?;
+        let handle = self.handle.clone();
+
+        Ok(JsCast::unchecked_into(future_to_promise(async move {
+            let states = futures_util::future::join_all(
+                addresses
+                    .iter()
+                    .map(|address| handle.as_ref().get_contract_state(address)),
+            )
+            .await;
+
+            // Failed fetches are returned as `null` to keep positions
+            Ok(states
+                .into_iter()
+                .map(|state| {
+                    state
+                        .ok()
+                        .and_then(|state| make_full_contract_state(state).ok())
+                        .unwrap_or_else(JsValue::null)
+                })
+                .collect::<js_sys::Array>()
+                .unchecked_into())
+        })))
+    }
+
     #[wasm_bindgen(js_name = "getAccountsByCodeHash")]
     pub fn get_accounts_by_code_hash(
         &self,
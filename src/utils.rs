@@ -4,6 +4,7 @@ use std::sync::Arc;
 use ton_block::{Deserializable, MsgAddressInt};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
+use zeroize::Zeroize;
 
 use nt::utils::TrustMe;
 
@@ -99,6 +100,15 @@ pub fn parse_public_key(public_key: &str) -> Result<ed25519_dalek::PublicKey, Js
         .handle_error()
 }
 
+pub fn parse_key_pair(secret_key: &str) -> Result<ed25519_dalek::Keypair, JsValue> {
+    let mut secret_key = parse_hex_or_base64_bytes(secret_key).handle_error()?;
+    let secret = ed25519_dalek::SecretKey::from_bytes(&secret_key).handle_error()?;
+    secret_key.zeroize();
+
+    let public = ed25519_dalek::PublicKey::from(&secret);
+    Ok(ed25519_dalek::Keypair { secret, public })
+}
+
 pub fn parse_signature(signature: &str) -> Result<ed25519_dalek::Signature, JsValue> {
     let signature = parse_base64_or_hex_bytes(signature).handle_error()?;
     match ed25519_dalek::Signature::try_from(signature.as_slice()) {
@@ -125,6 +135,12 @@ pub fn parse_cell(boc: &str) -> Result<ton_types::Cell, JsValue> {
     }
 }
 
+pub fn make_boc(cell: &ton_types::Cell) -> Result<String, JsValue> {
+    ton_types::serialize_toc(cell)
+        .map(base64::encode)
+        .handle_error()
+}
+
 pub fn parse_hex_or_base64_bytes(data: &str) -> Result<Vec<u8>, hex::FromHexError> {
     let data = data.trim();
     if data.is_empty() {
@@ -184,10 +200,37 @@ pub fn parse_account_stuff(boc: &str) -> Result<ton_block::AccountStuff, JsValue
         .handle_error()
 }
 
+pub fn get_account_data(
+    account_stuff: &ton_block::AccountStuff,
+) -> Result<&ton_types::Cell, JsValue> {
+    match &account_stuff.storage.state {
+        ton_block::AccountState::AccountActive { state_init, .. } => state_init
+            .data
+            .as_ref()
+            .ok_or(nt::abi::ExtractionError::AccountDataNotFound)
+            .handle_error(),
+        _ => Err(nt::abi::ExtractionError::AccountIsNotActive).handle_error(),
+    }
+}
+
+pub fn parse_config_params(boc: &str) -> Result<ton_block::ConfigParams, JsValue> {
+    ton_block::ConfigParams::construct_from_base64(boc.trim()).handle_error()
+}
+
 pub fn parse_contract_abi(contract_abi: &str) -> Result<ton_abi::Contract, JsValue> {
     ton_abi::Contract::load(contract_abi).handle_error()
 }
 
+pub fn parse_abi_version(version: &str) -> Result<ton_abi::contract::AbiVersion, JsValue> {
+    let (major, minor) = version
+        .trim()
+        .split_once('.')
+        .and_then(|(major, minor)| Some((u8::from_str(major).ok()?, u8::from_str(minor).ok()?)))
+        .ok_or("Invalid ABI version. Expected `major.minor`")
+        .handle_error()?;
+    Ok(ton_abi::contract::AbiVersion::from_parts(major, minor))
+}
+
 #[wasm_bindgen(typescript_custom_section)]
 const GENERAL_STUFF: &str = r#"
 export type EnumItem<T extends string, D> = { type: T, data: D };
@@ -227,6 +270,8 @@ pub enum TokensJsonError {
     ParameterNotFound(String),
     #[error("Invalid number: {}", .0)]
     InvalidNumber(String),
+    #[error("Number doesn't fit into {} bits: {}", .0, .1)]
+    NumberOutOfRange(usize, String),
     #[error("Expected integer value: {}", .0)]
     IntegerValueExpected(f64),
     #[error("Expected unsigned value: {}", .0)]